@@ -1,19 +1,65 @@
 use crate::core::config::DeviceSettings;
-use crate::core::sync::{apply_pkg_state_commands, CorePackage, Phone, User};
+use crate::core::sync::{apply_pkg_state_commands, CorePackage, PackageState, Phone, User};
 use crate::core::utils::DisplayablePath;
 use crate::gui::widgets::package_row::PackageRow;
 use crate::CACHE_DIR;
+use aes_gcm::aead::{Aead, KeyInit, OsRng as AeadOsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
+use chrono::{Datelike, NaiveDateTime};
+use rand::RngCore;
 use serde::{Deserialize, Serialize};
 use static_init::dynamic;
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, DirEntry};
 use std::path::{Path, PathBuf};
 
+/// `backup_phone` names files after the instant they were taken, e.g.
+/// `2023-09-14_18-05-22.json` (or `.json.zst` when compressed). Shared by
+/// the writer and the pruner so the two never drift apart.
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+
+/// Extension used for a compressed (and optionally encrypted) backup, on
+/// top of the timestamp: `2023-09-14_18-05-22.json.zst`.
+const COMPRESSED_BACKUP_EXTENSION: &str = ".json.zst";
+const PLAIN_BACKUP_EXTENSION: &str = ".json";
+
+/// Sidecar file written next to every backup so the selection UI can show
+/// a summary without parsing the (potentially compressed/encrypted) backup
+/// itself.
+const MANIFEST_EXTENSION: &str = ".manifest.json";
+
+/// Used instead of [`MANIFEST_EXTENSION`] when the backup it summarizes is
+/// encrypted: the manifest contains the same device identity and
+/// enabled/disabled/uninstalled package counts the backup is encrypted to
+/// protect, so it's encrypted under the same passphrase rather than left
+/// sitting next to it in the clear.
+const ENCRYPTED_MANIFEST_EXTENSION: &str = ".manifest.json.enc";
+
+/// Marks a compressed backup payload as additionally encrypted. Followed by
+/// a random salt, then a random nonce, then the AES-256-GCM ciphertext.
+const ENCRYPTED_BACKUP_MAGIC: &[u8] = b"UADENC1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
 #[dynamic]
 pub static BACKUP_DIR: PathBuf = CACHE_DIR.join("backups");
 
+/// Current on-disk backup format. Bump this whenever `PhoneBackup`'s shape
+/// changes and add a matching step to `migrate_backup`.
+const BACKUP_FORMAT_VERSION: u32 = 1;
+
 #[derive(Default, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 struct PhoneBackup {
+    #[serde(default)]
+    format_version: u32,
     device_id: String,
+    /// File name (not full path) of the backup this one is incremental
+    /// against. `None` means this is a standalone, full backup.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    base: Option<String>,
+    /// Full package state for a full backup, or only the packages whose
+    /// `state` changed since `base` for an incremental one.
     users: Vec<UserBackup>,
 }
 
@@ -23,33 +69,107 @@ struct UserBackup {
     packages: Vec<CorePackage>,
 }
 
-// Backup all `Uninstalled` and `Disabled` packages
+/// Summary of a backup, written as a `<timestamp>.manifest.json` sidecar so
+/// the selection UI can show device/package info without deserializing the
+/// (possibly large, compressed, or encrypted) backup file itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub device_id: String,
+    pub device_label: String,
+    pub format_version: u32,
+    pub app_version: String,
+    pub users: Vec<UserManifest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserManifest {
+    pub id: u16,
+    /// Package count per `PackageState`, keyed by its `Debug` form (e.g.
+    /// `"Uninstalled"`).
+    pub package_counts: HashMap<String, usize>,
+}
+
+/// A backup file paired with its manifest, for display in the selection UI.
+#[derive(Debug, Clone)]
+pub struct BackupEntry {
+    pub path: DisplayablePath,
+    pub manifest: BackupManifest,
+}
+
+/// Backs up every package in `phone_packages`, with its current state, for
+/// each user.
+///
+/// When `reference` is given (and `force_full` is `false`), the new backup
+/// is written as an incremental against it: only packages whose `state`
+/// changed since the reference are stored, plus a `base` pointer back to
+/// it. Pass `force_full: true` to always write a standalone snapshot, e.g.
+/// for a user-triggered "full backup" action even when a reference is
+/// available.
+///
+/// When `compress` is `true`, the backup is zstd-compressed and written
+/// with a `.json.zst` extension instead of `.json`. If `passphrase` is also
+/// given, the compressed payload is additionally encrypted (AES-256-GCM,
+/// key derived from the passphrase with Argon2) so the package list isn't
+/// readable at rest.
+///
+/// Passing a `passphrase` with `compress: false` is an error rather than a
+/// silent plaintext write: encryption only ever applies to the compressed
+/// payload, so the caller would otherwise get an unprotected backup with no
+/// indication the passphrase was ignored.
 pub async fn backup_phone(
     users: &[User],
     device_id: &str,
+    device_label: &str,
     phone_packages: &[Vec<PackageRow>],
+    reference: Option<&DisplayablePath>,
+    force_full: bool,
+    compress: bool,
+    passphrase: Option<&str>,
 ) -> Result<(), String> {
-    let backup = users.iter().enumerate().fold(
-        PhoneBackup {
-            device_id: device_id.to_string(),
-            ..Default::default()
-        },
-        |mut acc, (index, user)| {
-            let user_backup = UserBackup {
-                id: user.id,
-                packages: phone_packages[index]
-                    .iter()
-                    .map(|p| CorePackage {
-                        name: p.name.clone(),
-                        state: p.state,
-                    })
-                    .collect(),
-                ..Default::default()
-            };
-            acc.users.push(user_backup);
-            acc
-        },
-    );
+    if passphrase.is_some() && !compress {
+        return Err("Encryption requires compression: enable compression or drop the passphrase".to_string());
+    }
+
+    let live_users: Vec<UserBackup> = users
+        .iter()
+        .enumerate()
+        .map(|(index, user)| UserBackup {
+            id: user.id,
+            packages: phone_packages[index]
+                .iter()
+                .map(|p| CorePackage {
+                    name: p.name.clone(),
+                    state: p.state,
+                })
+                .collect(),
+        })
+        .collect();
+
+    let user_manifests: Vec<UserManifest> = live_users
+        .iter()
+        .map(|user| UserManifest {
+            id: user.id,
+            package_counts: package_state_counts(&user.packages),
+        })
+        .collect();
+
+    let (base, users) = match reference.filter(|_| !force_full) {
+        Some(reference) => {
+            let baseline = resolve_backup_chain(&reference.path, passphrase)?;
+            (
+                Some(backup_file_name(&reference.path)?),
+                diff_against_baseline(&live_users, &baseline.users),
+            )
+        }
+        None => (None, live_users),
+    };
+
+    let backup = PhoneBackup {
+        format_version: BACKUP_FORMAT_VERSION,
+        device_id: device_id.to_string(),
+        base,
+        users,
+    };
 
     let backup_path = BACKUP_DIR.join(device_id);
     if let Err(e) = fs::create_dir_all(&backup_path) {
@@ -57,43 +177,559 @@ pub async fn backup_phone(
         return Err(e.to_string());
     }
 
-    let backup_filename = format!("{}.json", chrono::Local::now().format("%Y-%m-%d_%H-%M-%S"));
     let json = serde_json::to_string_pretty(&backup).map_err(|e| e.to_string())?;
-    fs::write(backup_path.join(backup_filename), json).map_err(|e| e.to_string())?;
-    
+    let (extension, payload) = if compress {
+        let compressed =
+            zstd::stream::encode_all(json.as_bytes(), 0).map_err(|e| e.to_string())?;
+        let payload = match passphrase {
+            Some(passphrase) => encrypt_backup_bytes(&compressed, passphrase)?,
+            None => compressed,
+        };
+        (COMPRESSED_BACKUP_EXTENSION, payload)
+    } else {
+        (PLAIN_BACKUP_EXTENSION, json.into_bytes())
+    };
+
+    let timestamp = chrono::Local::now().format(BACKUP_TIMESTAMP_FORMAT).to_string();
+    let backup_filename = format!("{timestamp}{extension}");
+    fs::write(backup_path.join(backup_filename), payload).map_err(|e| e.to_string())?;
+
+    let manifest = BackupManifest {
+        device_id: device_id.to_string(),
+        device_label: device_label.to_string(),
+        format_version: BACKUP_FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        users: user_manifests,
+    };
+    let manifest_json = serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string())?;
+    let (manifest_extension, manifest_payload) = match passphrase {
+        Some(passphrase) => (
+            ENCRYPTED_MANIFEST_EXTENSION,
+            encrypt_backup_bytes(manifest_json.as_bytes(), passphrase)?,
+        ),
+        None => (MANIFEST_EXTENSION, manifest_json.into_bytes()),
+    };
+    let manifest_filename = format!("{timestamp}{manifest_extension}");
+    fs::write(backup_path.join(manifest_filename), manifest_payload).map_err(|e| e.to_string())?;
+
     Ok(())
 }
 
-pub fn list_available_backups(dir: &Path) -> Vec<DisplayablePath> {
+/// Counts a user's backed-up packages by their `PackageState`, keyed by its
+/// `Debug` form since `PackageState` lives in `core::sync` and isn't
+/// guaranteed to be hashable here.
+fn package_state_counts(packages: &[CorePackage]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for package in packages {
+        *counts.entry(format!("{:?}", package.state)).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Derives a 256-bit AES key from `passphrase` and `salt` with Argon2
+/// (default parameters), so a brute-forced passphrase guess costs real
+/// compute per attempt rather than a cheap hash.
+fn derive_backup_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Failed to derive encryption key: {e}"))?;
+    Ok(key)
+}
+
+/// Encrypts a backup payload (the compressed backup itself, or a
+/// manifest's plain JSON), prefixing it with [`ENCRYPTED_BACKUP_MAGIC`], a
+/// random salt and a random nonce so `decrypt_backup_bytes` can reverse it
+/// with only the passphrase.
+fn encrypt_backup_bytes(compressed: &[u8], passphrase: &str) -> Result<Vec<u8>, String> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    AeadOsRng.fill_bytes(&mut salt);
+    AeadOsRng.fill_bytes(&mut nonce_bytes);
+
+    let key = derive_backup_key(passphrase, &salt)?;
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    let nonce = Nonce::from_slice(&nonce_bytes);
+    let ciphertext = cipher
+        .encrypt(nonce, compressed)
+        .map_err(|e| format!("Failed to encrypt backup: {e}"))?;
+
+    let mut out =
+        Vec::with_capacity(ENCRYPTED_BACKUP_MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(ENCRYPTED_BACKUP_MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce_bytes);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// Reverses [`encrypt_backup_bytes`] for data whose
+/// [`ENCRYPTED_BACKUP_MAGIC`] prefix has already been stripped, returning
+/// the payload that was encrypted.
+fn decrypt_backup_bytes(data: &[u8], passphrase: &str) -> Result<Vec<u8>, String> {
+    if data.len() < SALT_LEN + NONCE_LEN {
+        return Err("Backup file is corrupt or not a valid backup".to_string());
+    }
+    let (salt, rest) = data.split_at(SALT_LEN);
+    let (nonce_bytes, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let key = derive_backup_key(passphrase, salt)?;
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    let nonce = Nonce::from_slice(nonce_bytes);
+
+    cipher
+        .decrypt(nonce, ciphertext)
+        .map_err(|_| "Wrong password, or the backup file is corrupt".to_string())
+}
+
+#[cfg(test)]
+mod encryption_tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_then_decrypt_recovers_the_original_bytes() {
+        let plaintext = b"{\"device_id\":\"test\",\"users\":[]}".to_vec();
+
+        let encrypted = encrypt_backup_bytes(&plaintext, "correct horse battery staple").unwrap();
+        assert!(encrypted.starts_with(ENCRYPTED_BACKUP_MAGIC));
+
+        let ciphertext = &encrypted[ENCRYPTED_BACKUP_MAGIC.len()..];
+        let decrypted =
+            decrypt_backup_bytes(ciphertext, "correct horse battery staple").unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn decrypt_with_the_wrong_passphrase_fails_cleanly() {
+        let plaintext = b"some backup payload".to_vec();
+        let encrypted = encrypt_backup_bytes(&plaintext, "right passphrase").unwrap();
+        let ciphertext = &encrypted[ENCRYPTED_BACKUP_MAGIC.len()..];
+
+        let result = decrypt_backup_bytes(ciphertext, "wrong passphrase");
+        assert!(result.is_err());
+    }
+}
+
+/// Reads the raw JSON for a backup file, transparently decompressing
+/// `.json.zst` files and decrypting them first if they carry
+/// [`ENCRYPTED_BACKUP_MAGIC`] (in which case `passphrase` is required).
+fn read_backup_payload(path: &Path, passphrase: Option<&str>) -> Result<String, String> {
+    let raw = fs::read(path).map_err(|e| e.to_string())?;
+
+    if path
+        .to_str()
+        .is_some_and(|p| !p.ends_with(COMPRESSED_BACKUP_EXTENSION))
+    {
+        return String::from_utf8(raw).map_err(|_| "Backup file is not valid UTF-8".to_string());
+    }
+
+    let compressed = match raw.strip_prefix(ENCRYPTED_BACKUP_MAGIC) {
+        Some(encrypted) => {
+            let passphrase = passphrase
+                .ok_or_else(|| "This backup is encrypted: a passphrase is required".to_string())?;
+            decrypt_backup_bytes(encrypted, passphrase)?
+        }
+        None => raw,
+    };
+
+    let decompressed = zstd::stream::decode_all(compressed.as_slice())
+        .map_err(|_| "Backup file is corrupt or not a valid backup".to_string())?;
+    String::from_utf8(decompressed)
+        .map_err(|_| "Backup file is corrupt or not a valid backup".to_string())
+}
+
+/// Keeps, for each live user, only the packages whose `state` differs from
+/// what `baseline` recorded for that user (new users are kept in full).
+///
+/// A package `baseline` tracked that's missing from `live` is recorded
+/// explicitly as `Enabled`: `fold_incremental` only ever adds or updates
+/// entries, so without that entry the fold would keep replaying the stale
+/// state from `baseline` forever.
+fn diff_against_baseline(live: &[UserBackup], baseline: &[UserBackup]) -> Vec<UserBackup> {
+    live.iter()
+        .map(|user| {
+            let baseline_packages = baseline
+                .iter()
+                .find(|b| b.id == user.id)
+                .map_or(&[][..], |b| b.packages.as_slice());
+
+            let mut packages: Vec<CorePackage> = user
+                .packages
+                .iter()
+                .filter(|p| !baseline_packages.contains(p))
+                .cloned()
+                .collect();
+
+            let live_names: HashSet<&str> =
+                user.packages.iter().map(|p| p.name.as_str()).collect();
+            packages.extend(baseline_packages.iter().filter_map(|b| {
+                (!live_names.contains(b.name.as_str())).then(|| CorePackage {
+                    name: b.name.clone(),
+                    state: PackageState::Enabled,
+                })
+            }));
+
+            UserBackup {
+                id: user.id,
+                packages,
+            }
+        })
+        .collect()
+}
+
+fn backup_file_name(path: &Path) -> Result<String, String> {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(str::to_string)
+        .ok_or_else(|| format!("Invalid backup filename: {}", path.display()))
+}
+
+/// Lists every backup file in `dir`, paired with its manifest, whether it's
+/// a plain `.json` backup or a compressed/encrypted `.json.zst` one.
+///
+/// When a backup has no readable manifest sidecar (made by an app version
+/// predating the manifest, or an encrypted manifest with no or the wrong
+/// `passphrase`), the manifest is rebuilt by lazily reading and resolving the
+/// full backup instead; `passphrase` is used for that fallback. A backup
+/// that can't be read or parsed at all is omitted.
+pub fn list_available_backups(dir: &Path, passphrase: Option<&str>) -> Vec<BackupEntry> {
     fs::read_dir(dir)
         .map(|entries| {
             entries
                 .filter_map(Result::ok)
-                .map(|entry: DirEntry| DisplayablePath { path: entry.path() })
+                .map(|entry: DirEntry| entry.path())
+                .filter(|path| {
+                    path.file_name().and_then(|name| name.to_str()).is_some_and(
+                        |name| {
+                            !name.ends_with(MANIFEST_EXTENSION)
+                                && !name.ends_with(ENCRYPTED_MANIFEST_EXTENSION)
+                        },
+                    )
+                })
+                .filter_map(|path| {
+                    let manifest = load_backup_manifest(&path, passphrase)
+                        .or_else(|| manifest_from_backup_file(&path, passphrase).ok())?;
+                    Some(BackupEntry {
+                        path: DisplayablePath { path },
+                        manifest,
+                    })
+                })
                 .collect()
         })
         .unwrap_or_default()
 }
 
-pub fn list_available_backup_users(backup: &DisplayablePath) -> Vec<User> {
-    match fs::read_to_string(&backup.path) {
-        Ok(data) => match serde_json::from_str::<PhoneBackup>(&data) {
-            Ok(phone_backup) => phone_backup
-                .users
-                .into_iter()
-                .map(|u| User {
-                    id: u.id,
-                    index: 0,
-                    protected: false,
-                })
-                .collect(),
-            Err(e) => {
-                error!("[BACKUP]: Failed to parse backup file: {}", e);
-                vec![]
+/// Reads and parses a backup's manifest sidecar, if any, decrypting it
+/// first with `passphrase` when it was written encrypted.
+pub fn load_backup_manifest(backup_path: &Path, passphrase: Option<&str>) -> Option<BackupManifest> {
+    let manifest_path = manifest_path_for(backup_path)?;
+    let raw = fs::read(&manifest_path).ok()?;
+
+    let json = if manifest_path
+        .to_str()
+        .is_some_and(|p| p.ends_with(ENCRYPTED_MANIFEST_EXTENSION))
+    {
+        let encrypted = raw.strip_prefix(ENCRYPTED_BACKUP_MAGIC)?;
+        let decrypted = decrypt_backup_bytes(encrypted, passphrase?).ok()?;
+        String::from_utf8(decrypted).ok()?
+    } else {
+        String::from_utf8(raw).ok()?
+    };
+
+    serde_json::from_str(&json).ok()
+}
+
+/// Finds whichever manifest sidecar (plain or encrypted) exists for a
+/// backup file.
+fn manifest_path_for(backup_path: &Path) -> Option<PathBuf> {
+    let file_name = backup_path.file_name()?.to_str()?;
+    let stem = file_name
+        .strip_suffix(COMPRESSED_BACKUP_EXTENSION)
+        .or_else(|| file_name.strip_suffix(PLAIN_BACKUP_EXTENSION))?;
+
+    let plain = backup_path.with_file_name(format!("{stem}{MANIFEST_EXTENSION}"));
+    if plain.exists() {
+        return Some(plain);
+    }
+    let encrypted = backup_path.with_file_name(format!("{stem}{ENCRYPTED_MANIFEST_EXTENSION}"));
+    encrypted.exists().then_some(encrypted)
+}
+
+/// Rebuilds a manifest by reading and fully resolving a backup that has no
+/// sidecar of its own. The app version that wrote it is unknown.
+fn manifest_from_backup_file(
+    backup_path: &Path,
+    passphrase: Option<&str>,
+) -> Result<BackupManifest, String> {
+    let backup = resolve_backup_chain(backup_path, passphrase)?;
+    Ok(BackupManifest {
+        device_id: backup.device_id.clone(),
+        device_label: backup.device_id,
+        format_version: backup.format_version,
+        app_version: "unknown".to_string(),
+        users: backup
+            .users
+            .iter()
+            .map(|user| UserManifest {
+                id: user.id,
+                package_counts: package_state_counts(&user.packages),
+            })
+            .collect(),
+    })
+}
+
+/// Parses a raw backup file, transparently upgrading older `format_version`s
+/// to the current `PhoneBackup` shape and rejecting backups written by a
+/// newer version of the app than this one understands.
+fn parse_phone_backup(data: &str) -> Result<PhoneBackup, String> {
+    let value: serde_json::Value = serde_json::from_str(data).map_err(|e| e.to_string())?;
+    let from_version = value
+        .get("format_version")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0) as u32;
+
+    match from_version.cmp(&BACKUP_FORMAT_VERSION) {
+        std::cmp::Ordering::Greater => Err(format!(
+            "Backup was created by a newer version of the app (format version {from_version}, this version only understands up to {BACKUP_FORMAT_VERSION})"
+        )),
+        std::cmp::Ordering::Equal => serde_json::from_value(value).map_err(|e| e.to_string()),
+        std::cmp::Ordering::Less => migrate_backup(value, from_version),
+    }
+}
+
+/// Upgrades a backup's raw JSON from `from` to [`BACKUP_FORMAT_VERSION`],
+/// applying each version step in turn so older backups keep working after
+/// an app update.
+fn migrate_backup(mut value: serde_json::Value, from: u32) -> Result<PhoneBackup, String> {
+    if from == 0 {
+        // v0 was the original unversioned shape: just stamp the field in.
+        if let Some(obj) = value.as_object_mut() {
+            obj.insert("format_version".to_string(), serde_json::json!(1));
+        }
+    }
+
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod migration_tests {
+    use super::*;
+
+    #[test]
+    fn parse_phone_backup_migrates_the_unversioned_v0_shape_to_v1() {
+        let v0 = r#"{"device_id":"test","users":[{"id":0,"packages":[]}]}"#;
+
+        let backup = parse_phone_backup(v0).unwrap();
+
+        assert_eq!(backup.format_version, BACKUP_FORMAT_VERSION);
+        assert_eq!(backup.device_id, "test");
+    }
+
+    #[test]
+    fn parse_phone_backup_rejects_a_backup_from_a_newer_app_version() {
+        let from_the_future = r#"{"format_version":999,"device_id":"test","users":[]}"#;
+
+        let result = parse_phone_backup(from_the_future);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("newer version"));
+    }
+}
+
+/// Loads the backup at `path`, walking back through `base` pointers to the
+/// full baseline and folding each incremental forward (newest wins per
+/// package, per user) so callers always see a complete, standalone
+/// `PhoneBackup`. Errors clearly if a referenced base is missing.
+///
+/// `passphrase` is used to decrypt every encrypted backup in the chain; a
+/// device's backups are expected to share one passphrase.
+fn resolve_backup_chain(path: &Path, passphrase: Option<&str>) -> Result<PhoneBackup, String> {
+    resolve_backup_chain_visiting(path, passphrase, &mut HashSet::new())
+}
+
+/// Does the work of [`resolve_backup_chain`], tracking the file names
+/// visited so far so a `base` cycle errors out instead of recursing
+/// forever.
+fn resolve_backup_chain_visiting(
+    path: &Path,
+    passphrase: Option<&str>,
+    visited: &mut HashSet<String>,
+) -> Result<PhoneBackup, String> {
+    let file_name = backup_file_name(path)?;
+    if !visited.insert(file_name.clone()) {
+        return Err(format!(
+            "Broken backup chain: '{file_name}' is part of a cycle through its own base backups"
+        ));
+    }
+
+    let data = read_backup_payload(path, passphrase)?;
+    let backup = parse_phone_backup(&data)?;
+
+    let Some(base_name) = &backup.base else {
+        return Ok(backup);
+    };
+
+    let base_path = path
+        .parent()
+        .ok_or_else(|| format!("Backup path {} has no parent directory", path.display()))?
+        .join(base_name);
+    if !base_path.exists() {
+        return Err(format!(
+            "Broken backup chain: base backup '{base_name}' referenced by {} no longer exists",
+            path.display()
+        ));
+    }
+
+    let mut baseline = resolve_backup_chain_visiting(&base_path, passphrase, visited)?;
+    fold_incremental(&mut baseline, backup.users);
+    baseline.base = None;
+    Ok(baseline)
+}
+
+/// Applies an incremental backup's package changes onto a resolved
+/// baseline, newest wins per package/user.
+fn fold_incremental(baseline: &mut PhoneBackup, incremental_users: Vec<UserBackup>) {
+    for incremental_user in incremental_users {
+        match baseline
+            .users
+            .iter_mut()
+            .find(|u| u.id == incremental_user.id)
+        {
+            Some(user) => {
+                for package in incremental_user.packages {
+                    match user.packages.iter_mut().find(|p| p.name == package.name) {
+                        Some(existing) => existing.state = package.state,
+                        None => user.packages.push(package),
+                    }
+                }
             }
-        },
+            None => baseline.users.push(incremental_user),
+        }
+    }
+}
+
+#[cfg(test)]
+mod chain_tests {
+    use super::*;
+
+    #[test]
+    fn fold_incremental_lets_the_newer_state_win_and_keeps_unchanged_packages() {
+        let mut baseline = PhoneBackup {
+            format_version: BACKUP_FORMAT_VERSION,
+            device_id: "test".to_string(),
+            base: None,
+            users: vec![UserBackup {
+                id: 0,
+                packages: vec![
+                    CorePackage {
+                        name: "com.kept.enabled".to_string(),
+                        state: PackageState::Enabled,
+                    },
+                    CorePackage {
+                        name: "com.flip.disabled".to_string(),
+                        state: PackageState::Disabled,
+                    },
+                ],
+            }],
+        };
+
+        let incremental = vec![
+            UserBackup {
+                id: 0,
+                packages: vec![
+                    CorePackage {
+                        name: "com.flip.disabled".to_string(),
+                        state: PackageState::Uninstalled,
+                    },
+                    CorePackage {
+                        name: "com.new.uninstalled".to_string(),
+                        state: PackageState::Uninstalled,
+                    },
+                ],
+            },
+            UserBackup {
+                id: 1,
+                packages: vec![CorePackage {
+                    name: "com.newuser.disabled".to_string(),
+                    state: PackageState::Disabled,
+                }],
+            },
+        ];
+
+        fold_incremental(&mut baseline, incremental);
+
+        let user0 = baseline.users.iter().find(|u| u.id == 0).unwrap();
+        assert_eq!(user0.packages.len(), 3);
+        assert_eq!(
+            user0
+                .packages
+                .iter()
+                .find(|p| p.name == "com.kept.enabled")
+                .unwrap()
+                .state,
+            PackageState::Enabled
+        );
+        assert_eq!(
+            user0
+                .packages
+                .iter()
+                .find(|p| p.name == "com.flip.disabled")
+                .unwrap()
+                .state,
+            PackageState::Uninstalled
+        );
+        assert!(user0.packages.iter().any(|p| p.name == "com.new.uninstalled"));
+
+        let user1 = baseline.users.iter().find(|u| u.id == 1).unwrap();
+        assert_eq!(user1.packages.len(), 1);
+    }
+
+    #[test]
+    fn resolve_backup_chain_errors_on_a_cycle_instead_of_recursing_forever() {
+        let dir = std::env::temp_dir().join(format!(
+            "uad-save-test-cycle-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+
+        fs::write(
+            dir.join("a.json"),
+            r#"{"device_id":"test","base":"b.json","users":[]}"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("b.json"),
+            r#"{"device_id":"test","base":"a.json","users":[]}"#,
+        )
+        .unwrap();
+
+        let result = resolve_backup_chain(&dir.join("a.json"), None);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("cycle"));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
+
+pub fn list_available_backup_users(
+    backup: &DisplayablePath,
+    passphrase: Option<&str>,
+) -> Vec<User> {
+    match resolve_backup_chain(&backup.path, passphrase) {
+        Ok(phone_backup) => phone_backup
+            .users
+            .into_iter()
+            .map(|u| User {
+                id: u.id,
+                index: 0,
+                protected: false,
+            })
+            .collect(),
         Err(e) => {
-            error!("[BACKUP]: Selected backup file not found: {}", e);
+            error!("[BACKUP]: Failed to parse backup file: {}", e);
             vec![]
         }
     }
@@ -109,6 +745,7 @@ pub fn restore_backup(
     selected_device: &Phone,
     packages: &[Vec<PackageRow>],
     settings: &DeviceSettings,
+    passphrase: Option<&str>,
 ) -> Result<Vec<BackupPackage>, String> {
     let backup_path = settings
         .backup
@@ -118,8 +755,7 @@ pub fn restore_backup(
         .path
         .clone();
 
-    let data = fs::read_to_string(&backup_path).map_err(|e| e.to_string())?;
-    let phone_backup: PhoneBackup = serde_json::from_str(&data).map_err(|e| e.to_string())?;
+    let phone_backup = resolve_backup_chain(&backup_path, passphrase)?;
 
     let mut commands = Vec::new();
     let selected_user = settings
@@ -168,3 +804,244 @@ pub fn restore_backup(
 
     Ok(commands)
 }
+
+/// How many backups to keep per retention bucket when pruning a device's
+/// backup directory. `keep_last` counts backups; each other field is a
+/// count of buckets, not of files: `keep_daily: 3` keeps the newest backup from each of the 3 most recent
+/// days that have one, not the 3 most recent backups. A backup that's
+/// outside every bucket below is only removed if nothing still
+/// incrementally chains to it (see `plan_prune`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneOptions {
+    pub keep_last: usize,
+    pub keep_daily: usize,
+    pub keep_weekly: usize,
+    pub keep_monthly: usize,
+}
+
+impl PruneOptions {
+    /// A policy that keeps nothing would prune every backup in the
+    /// directory, which is almost certainly a mistake rather than intent.
+    pub fn keeps_something(&self) -> bool {
+        self.keep_last > 0 || self.keep_daily > 0 || self.keep_weekly > 0 || self.keep_monthly > 0
+    }
+}
+
+/// What `prune_backups` would keep and remove, computed without touching
+/// the filesystem so the GUI can show the user what's about to be deleted
+/// before they commit to it.
+#[derive(Debug, Default)]
+pub struct PrunePlan {
+    pub keep: Vec<DisplayablePath>,
+    pub remove: Vec<DisplayablePath>,
+}
+
+fn parse_backup_timestamp(entry: &DirEntry) -> Option<NaiveDateTime> {
+    let file_name = entry.path().file_name()?.to_str()?.to_string();
+    let stem = file_name
+        .strip_suffix(COMPRESSED_BACKUP_EXTENSION)
+        .or_else(|| file_name.strip_suffix(PLAIN_BACKUP_EXTENSION))?;
+    NaiveDateTime::parse_from_str(stem, BACKUP_TIMESTAMP_FORMAT).ok()
+}
+
+/// Keeps the newest entry of each bucket (as computed by `key_fn`), for the
+/// `limit` most-recent distinct buckets. `sorted` must be newest-first.
+fn keep_newest_per_bucket<K: Eq + std::hash::Hash>(
+    sorted: &[(NaiveDateTime, DisplayablePath)],
+    limit: usize,
+    key_fn: impl Fn(&NaiveDateTime) -> K,
+) -> HashSet<usize> {
+    let mut seen_buckets = HashSet::new();
+    let mut kept = HashSet::new();
+
+    for (i, (timestamp, _)) in sorted.iter().enumerate() {
+        if seen_buckets.len() >= limit {
+            break;
+        }
+        if seen_buckets.insert(key_fn(timestamp)) {
+            kept.insert(i);
+        }
+    }
+
+    kept
+}
+
+/// Extends `kept_indices` with every ancestor (direct or transitive `base`)
+/// of an already-kept backup, so pruning never removes a full backup or
+/// intermediate incremental that a surviving backup still chains to.
+fn keep_referenced_ancestors(
+    entries: &[(NaiveDateTime, DisplayablePath)],
+    kept_indices: &mut HashSet<usize>,
+    passphrase: Option<&str>,
+) -> Result<(), String> {
+    let index_by_name: HashMap<String, usize> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (_, path))| Some((backup_file_name(&path.path).ok()?, i)))
+        .collect();
+
+    let mut frontier: Vec<usize> = kept_indices.iter().copied().collect();
+    while let Some(i) = frontier.pop() {
+        let base_name = read_backup_base(&entries[i].1.path, passphrase)?;
+        let Some(base_name) = base_name else {
+            continue;
+        };
+        let Some(&base_index) = index_by_name.get(&base_name) else {
+            // The base isn't in this directory listing (already gone); that's
+            // a pre-existing broken chain, not something pruning caused.
+            continue;
+        };
+        if kept_indices.insert(base_index) {
+            frontier.push(base_index);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads just the `base` pointer out of a backup file, without resolving
+/// the rest of its chain.
+fn read_backup_base(path: &Path, passphrase: Option<&str>) -> Result<Option<String>, String> {
+    let data = read_backup_payload(path, passphrase)?;
+    Ok(parse_phone_backup(&data)?.base)
+}
+
+/// Computes which backups in `dir` a [`PruneOptions`] policy would keep and
+/// remove, without deleting anything. A backup the bucket rules would
+/// otherwise remove is kept anyway if a surviving backup's `base` chain
+/// (see chunk0-3's incremental backups) still references it, so restoring
+/// a kept incremental never breaks. `passphrase` is used to read the `base`
+/// pointer of encrypted backups.
+pub fn plan_prune(
+    dir: &Path,
+    opts: &PruneOptions,
+    passphrase: Option<&str>,
+) -> Result<PrunePlan, String> {
+    let mut entries: Vec<(NaiveDateTime, DisplayablePath)> = fs::read_dir(dir)
+        .map_err(|e| e.to_string())?
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let timestamp = parse_backup_timestamp(&entry)?;
+            Some((timestamp, DisplayablePath { path: entry.path() }))
+        })
+        .collect();
+    entries.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    let mut kept_indices = HashSet::new();
+    kept_indices.extend(0..entries.len().min(opts.keep_last));
+    kept_indices.extend(keep_newest_per_bucket(&entries, opts.keep_daily, |ts| {
+        ts.date()
+    }));
+    kept_indices.extend(keep_newest_per_bucket(&entries, opts.keep_weekly, |ts| {
+        let week = ts.iso_week();
+        (week.year(), week.week())
+    }));
+    kept_indices.extend(keep_newest_per_bucket(&entries, opts.keep_monthly, |ts| {
+        (ts.date().year(), ts.date().month())
+    }));
+    keep_referenced_ancestors(&entries, &mut kept_indices, passphrase)?;
+
+    let mut plan = PrunePlan::default();
+    for (i, (_, path)) in entries.into_iter().enumerate() {
+        if kept_indices.contains(&i) {
+            plan.keep.push(path);
+        } else {
+            plan.remove.push(path);
+        }
+    }
+
+    Ok(plan)
+}
+
+/// Deletes the backups in `dir` that a [`PruneOptions`] policy doesn't keep,
+/// refusing to run a policy that would keep nothing. Returns the list of
+/// removed files.
+pub fn prune_backups(
+    dir: &Path,
+    opts: &PruneOptions,
+    passphrase: Option<&str>,
+) -> Result<Vec<DisplayablePath>, String> {
+    if !opts.keeps_something() {
+        return Err("Prune policy keeps nothing: refusing to delete every backup".to_string());
+    }
+
+    let plan = plan_prune(dir, opts, passphrase)?;
+    for path in &plan.remove {
+        fs::remove_file(&path.path).map_err(|e| e.to_string())?;
+        if let Some(manifest_path) = manifest_path_for(&path.path) {
+            let _ = fs::remove_file(manifest_path);
+        }
+    }
+
+    Ok(plan.remove)
+}
+
+#[cfg(test)]
+mod prune_tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_backup_dir(label: &str) -> PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("uad-save-test-{label}-{nonce}"));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_backup(dir: &Path, timestamp: &str, base: Option<&str>) {
+        let base_field = base.map_or_else(String::new, |b| format!(r#""base":"{b}","#));
+        let path = dir.join(format!("{timestamp}.json"));
+        fs::write(
+            path,
+            format!(r#"{{"device_id":"test",{base_field}"users":[]}}"#),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn plan_prune_keeps_only_the_newest_backup_per_day_bucket() {
+        let dir = temp_backup_dir("daily");
+        write_backup(&dir, "2024-01-01_08-00-00", None);
+        write_backup(&dir, "2024-01-01_20-00-00", None);
+        write_backup(&dir, "2023-12-31_20-00-00", None);
+
+        let opts = PruneOptions {
+            keep_daily: 1,
+            ..Default::default()
+        };
+        let plan = plan_prune(&dir, &opts, None).unwrap();
+
+        assert_eq!(plan.keep.len(), 1);
+        assert!(plan.keep[0].path.ends_with("2024-01-01_20-00-00.json"));
+        assert_eq!(plan.remove.len(), 2);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn plan_prune_keeps_a_base_still_referenced_by_a_kept_incremental() {
+        let dir = temp_backup_dir("chain");
+        write_backup(&dir, "2024-01-01_08-00-00", None);
+        write_backup(
+            &dir,
+            "2024-01-02_08-00-00",
+            Some("2024-01-01_08-00-00.json"),
+        );
+
+        // keep_last: 1 would normally only keep the newest file, but the
+        // full backup it chains to must survive alongside it.
+        let opts = PruneOptions {
+            keep_last: 1,
+            ..Default::default()
+        };
+        let plan = plan_prune(&dir, &opts, None).unwrap();
+
+        assert_eq!(plan.keep.len(), 2);
+        assert!(plan.remove.is_empty());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}